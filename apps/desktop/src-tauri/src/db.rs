@@ -0,0 +1,31 @@
+use std::sync::Mutex;
+
+use rusqlite::Connection;
+use tauri::{AppHandle, Manager};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project TEXT NOT NULL,
+    task TEXT NOT NULL,
+    started_at INTEGER NOT NULL,
+    ended_at INTEGER
+);
+";
+
+/// Managed SQLite connection backing the timesheet. Wrapped in a mutex so
+/// it can be shared across command handlers.
+pub struct Db(pub Mutex<Connection>);
+
+/// Opens (creating if needed) the SQLite store under the app data dir and
+/// applies the schema, so time entries survive webview reloads and crashes.
+pub fn init(app: &AppHandle) -> rusqlite::Result<Db> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .expect("app data dir is always resolvable");
+    std::fs::create_dir_all(&dir).expect("can create app data dir");
+    let conn = Connection::open(dir.join("timesheet.sqlite3"))?;
+    conn.execute_batch(SCHEMA)?;
+    Ok(Db(Mutex::new(conn)))
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_deep_link::DeepLinkExt;
+
+use crate::entries;
+use crate::ready::Ready;
+
+/// Interprets a `timesheet://` URL (`timesheet://start?project=Acme&task=Billing`,
+/// `timesheet://stop`) and drives the same commands the frontend would call,
+/// so external tools, calendar apps, or browser buttons can control tracking.
+///
+/// No-ops until backend warm-up has finished: a second-instance launch can
+/// forward a URL here before `Db` is managed, and routing it any earlier
+/// would panic on the missing state instead of just missing this one link.
+pub fn route(app: &AppHandle, url: &str) {
+    if !app.state::<Ready>().get() {
+        return;
+    }
+
+    let Ok(url) = url::Url::parse(url) else {
+        return;
+    };
+    if url.scheme() != "timesheet" {
+        return;
+    }
+
+    match url.host_str() {
+        Some("start") => {
+            let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+            let project = params.get("project").cloned().unwrap_or_default();
+            let task = params.get("task").cloned().unwrap_or_default();
+            let _ = entries::start_entry(app.clone(), app.state(), app.state(), project, task);
+        }
+        Some("stop") => {
+            let _ = entries::stop_entry(app.clone(), app.state(), app.state());
+        }
+        _ => {}
+    }
+}
+
+/// Registers the deep-link `on_open_url` handler and replays any URL the
+/// app was launched with, routing both through the same [`route`].
+pub fn register(app: &AppHandle) {
+    let handle = app.clone();
+    app.deep_link().on_open_url(move |event| {
+        for url in event.urls() {
+            route(&handle, url.as_str());
+        }
+    });
+
+    if let Ok(urls) = app.deep_link().get_current() {
+        for url in urls.unwrap_or_default() {
+            route(app, url.as_str());
+        }
+    }
+}
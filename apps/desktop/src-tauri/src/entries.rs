@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, State};
+
+use crate::db::Db;
+use crate::events;
+use crate::time::unix_now;
+use crate::timer::{TimerState, TimerStatus};
+
+/// A single tracked span of work, persisted in the `entries` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: i64,
+    pub project: String,
+    pub task: String,
+    pub started_at: i64,
+    pub ended_at: Option<i64>,
+}
+
+fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<TimeEntry> {
+    Ok(TimeEntry {
+        id: row.get(0)?,
+        project: row.get(1)?,
+        task: row.get(2)?,
+        started_at: row.get(3)?,
+        ended_at: row.get(4)?,
+    })
+}
+
+#[tauri::command]
+pub fn start_entry(
+    app: AppHandle,
+    db: State<Db>,
+    timer: State<TimerState>,
+    project: String,
+    task: String,
+) -> Result<TimeEntry, String> {
+    if timer.status() != TimerStatus::Idle {
+        return Err("a timer is already running".into());
+    }
+
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let started_at = unix_now();
+    conn.execute(
+        "INSERT INTO entries (project, task, started_at, ended_at) VALUES (?1, ?2, ?3, NULL)",
+        rusqlite::params![project, task, started_at],
+    )
+    .map_err(|e| e.to_string())?;
+    let id = conn.last_insert_rowid();
+
+    timer.start(id, project.clone(), task.clone());
+    events::emit_state(&app, &timer);
+
+    Ok(TimeEntry {
+        id,
+        project,
+        task,
+        started_at,
+        ended_at: None,
+    })
+}
+
+#[tauri::command]
+pub fn stop_entry(app: AppHandle, db: State<Db>, timer: State<TimerState>) -> Result<(), String> {
+    // Scope the update to the entry the timer is actually tracking, so a
+    // leftover dangling open row left by some other path can't get closed
+    // (or merged at the wrong timestamp) as a side effect of stopping.
+    if let Some(entry_id) = timer.entry_id() {
+        db.0.lock()
+            .map_err(|e| e.to_string())?
+            .execute(
+                "UPDATE entries SET ended_at = ?1 WHERE id = ?2 AND ended_at IS NULL",
+                rusqlite::params![unix_now(), entry_id],
+            )
+            .map_err(|e| e.to_string())?;
+    }
+
+    timer.stop();
+    events::emit_state(&app, &timer);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_entries(db: State<Db>, range: Option<(i64, i64)>) -> Result<Vec<TimeEntry>, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+
+    let (query, params): (&str, Vec<i64>) = match range {
+        Some((from, to)) => (
+            "SELECT id, project, task, started_at, ended_at FROM entries \
+             WHERE started_at >= ?1 AND started_at < ?2 ORDER BY started_at DESC",
+            vec![from, to],
+        ),
+        None => (
+            "SELECT id, project, task, started_at, ended_at FROM entries ORDER BY started_at DESC",
+            vec![],
+        ),
+    };
+
+    let mut stmt = conn.prepare(query).map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(rusqlite::params_from_iter(params), row_to_entry)
+        .map_err(|e| e.to_string())?;
+
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn edit_entry(
+    db: State<Db>,
+    id: i64,
+    project: Option<String>,
+    task: Option<String>,
+    started_at: Option<i64>,
+    ended_at: Option<i64>,
+) -> Result<(), String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+
+    if let Some(project) = project {
+        conn.execute(
+            "UPDATE entries SET project = ?1 WHERE id = ?2",
+            rusqlite::params![project, id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    if let Some(task) = task {
+        conn.execute(
+            "UPDATE entries SET task = ?1 WHERE id = ?2",
+            rusqlite::params![task, id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    if let Some(started_at) = started_at {
+        conn.execute(
+            "UPDATE entries SET started_at = ?1 WHERE id = ?2",
+            rusqlite::params![started_at, id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    if let Some(ended_at) = ended_at {
+        conn.execute(
+            "UPDATE entries SET ended_at = ?1 WHERE id = ?2",
+            rusqlite::params![ended_at, id],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_entry(db: State<Db>, id: i64) -> Result<(), String> {
+    db.0
+        .lock()
+        .map_err(|e| e.to_string())?
+        .execute("DELETE FROM entries WHERE id = ?1", rusqlite::params![id])
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::timer::{TimerState, TimerStatus};
+use crate::tray;
+
+const TICK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Payload for `timer://tick`, broadcast once a second while a timer is
+/// running.
+#[derive(Debug, Clone, Serialize)]
+pub struct TickPayload {
+    pub entry_id: Option<i64>,
+    pub elapsed_secs: u64,
+}
+
+/// Payload for `timer://state`, broadcast whenever the timer starts, stops,
+/// or pauses, regardless of which surface (tray, shortcut, deep link,
+/// frontend command) triggered the change.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatePayload {
+    pub status: TimerStatus,
+    pub entry_id: Option<i64>,
+}
+
+/// Emits a `timer://state` event for the timer's current status. Every
+/// entry point that mutates [`TimerState`] calls this afterwards so the
+/// webview, tray, and any other listener all see the same state changes.
+pub fn emit_state(app: &AppHandle, timer: &TimerState) {
+    let _ = app.emit(
+        "timer://state",
+        StatePayload {
+            status: timer.status(),
+            entry_id: timer.entry_id(),
+        },
+    );
+}
+
+/// Spawns the single authoritative clock: once a second, refreshes the tray
+/// label and, while a timer is running, broadcasts `timer://tick` with the
+/// running entry id and accumulated seconds. Having one spawned task own
+/// this avoids drift between multiple JS timers or duplicate ticking loops.
+pub fn spawn_ticker(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(TICK_INTERVAL).await;
+
+            let timer = app.state::<TimerState>();
+            let status = timer.status();
+
+            let label = match status {
+                TimerStatus::Idle => "Timesheet \u{2014} idle".to_string(),
+                TimerStatus::Running => format!("\u{25b6} {}", timer.label()),
+                TimerStatus::Paused => format!("\u{23f8} {}", timer.label()),
+            };
+            tray::refresh(&app, &label);
+
+            if status == TimerStatus::Running {
+                let _ = app.emit(
+                    "timer://tick",
+                    TickPayload {
+                        entry_id: timer.entry_id(),
+                        elapsed_secs: timer.elapsed().as_secs(),
+                    },
+                );
+            }
+        }
+    });
+}
@@ -0,0 +1,267 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::db::Db;
+use crate::events;
+use crate::time::unix_now;
+use crate::timer::{TimerState, TimerStatus};
+
+const IDLE_THRESHOLD: Duration = Duration::from_secs(5 * 60);
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
+struct IdleInner {
+    last_active: Instant,
+    /// Set to the instant the current idle span began once the threshold is
+    /// crossed, and cleared again as soon as activity resumes. Its presence
+    /// is what guarantees only one idle prompt per idle span.
+    idle_since: Option<Instant>,
+    /// The still-unresolved idle span last reported via `timer://idle-detected`,
+    /// keyed by the entry it happened on. `resolve_idle` consumes this so it
+    /// can trim the entry at the real idle start instead of "now".
+    pending: Option<(i64, i64)>,
+}
+
+impl Default for IdleInner {
+    fn default() -> Self {
+        Self {
+            last_active: Instant::now(),
+            idle_since: None,
+            pending: None,
+        }
+    }
+}
+
+/// Tracks user input activity so a running timer can be flagged idle after
+/// a period of inactivity and retroactively trimmed once the user returns.
+#[derive(Debug, Default)]
+pub struct IdleState(Mutex<IdleInner>);
+
+impl IdleState {
+    fn mark_idle_if_due(&self) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.idle_since.is_none() && inner.last_active.elapsed() >= IDLE_THRESHOLD {
+            inner.idle_since = Some(inner.last_active);
+        }
+    }
+
+    /// Records activity and, if the span had been marked idle, returns how
+    /// long it lasted so the caller can report it.
+    fn resume(&self) -> Option<Duration> {
+        let mut inner = self.0.lock().unwrap();
+        let idle_since = inner.idle_since.take();
+        inner.last_active = Instant::now();
+        idle_since.map(|since| since.elapsed())
+    }
+
+    fn set_pending(&self, entry_id: i64, idle_started_at: i64) {
+        self.0.lock().unwrap().pending = Some((entry_id, idle_started_at));
+    }
+
+    /// Takes the pending idle-start timestamp for `entry_id`, if the last
+    /// detected idle span was on that entry and hasn't been resolved yet.
+    fn take_pending(&self, entry_id: i64) -> Option<i64> {
+        let mut inner = self.0.lock().unwrap();
+        match inner.pending {
+            Some((pending_id, idle_started_at)) if pending_id == entry_id => {
+                inner.pending = None;
+                Some(idle_started_at)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct IdleDetectedPayload {
+    entry_id: Option<i64>,
+    idle_started_at: i64,
+    idle_secs: u64,
+}
+
+/// Records activity (pointer/keyboard input, window focus) and, if the
+/// timer had been sitting idle, emits `timer://idle-detected` with the idle
+/// span so the frontend can prompt the user to resolve it.
+pub fn note_activity(app: &AppHandle) {
+    let idle = app.state::<IdleState>();
+    let Some(duration) = idle.resume() else {
+        return;
+    };
+
+    let timer = app.state::<TimerState>();
+    let idle_started_at = unix_now() - duration.as_secs() as i64;
+
+    if let Some(entry_id) = timer.entry_id() {
+        app.state::<IdleState>().set_pending(entry_id, idle_started_at);
+    }
+
+    let _ = app.emit(
+        "timer://idle-detected",
+        IdleDetectedPayload {
+            entry_id: timer.entry_id(),
+            idle_started_at,
+            idle_secs: duration.as_secs(),
+        },
+    );
+}
+
+/// Frontend-facing counterpart to [`note_activity`], invoked on mouse/key
+/// input the webview observes.
+#[tauri::command]
+pub fn report_activity(app: AppHandle) {
+    note_activity(&app);
+}
+
+/// Spawns the background task that compares time-since-last-activity
+/// against [`IDLE_THRESHOLD`] while a timer is running, flipping the idle
+/// flag exactly once per idle span.
+pub fn spawn_monitor(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            if app.state::<TimerState>().status() != TimerStatus::Running {
+                continue;
+            }
+            app.state::<IdleState>().mark_idle_if_due();
+        }
+    });
+}
+
+/// Pure clamping math behind [`resolve_idle`]'s discard/split: the cut point
+/// is the real idle start, but never before `started_at` (an idle span can't
+/// predate the entry it was measured on); a split's resume point is never
+/// before that cut and never after `now` (a `split_at` before the cut resumes
+/// at the cut rather than backdating into the trimmed entry, and one after
+/// `now` resumes at `now`).
+fn clamp_idle(
+    started_at: i64,
+    idle_started_at: i64,
+    split_at: Option<i64>,
+    now: i64,
+) -> (i64, Option<i64>) {
+    let cut = idle_started_at.max(started_at);
+    (cut, split_at.map(|split_at| split_at.max(cut).min(now)))
+}
+
+/// Resolves an idle prompt for `entry_id`: keeps the idle time as tracked
+/// (`keep = true`), discards it by ending the entry where the idle span
+/// actually began (`keep = false`, no `split_at`), or does the same and
+/// also opens a fresh entry starting at `split_at` to continue tracking.
+/// The cut point always comes from the idle span [`IdleState`] recorded for
+/// this entry via `timer://idle-detected`, not from when this command
+/// happens to be called — otherwise "discard" would just silently keep
+/// counting the idle time up to that point. An entry's `ended_at` is always
+/// clamped to its `started_at` so trimming can never produce a
+/// negative-duration entry.
+///
+/// A discard or split closes `entry_id` in the DB, so [`TimerState`] is
+/// rebound to match: cleared on a plain discard, or re-pointed at the new
+/// entry on a split. Otherwise the shared timer would keep ticking against
+/// a row that's already closed, and a later `stop_entry` would no-op
+/// against it while the split's replacement entry stays open forever.
+///
+/// Scoped to the entry [`TimerState`] is actually tracking, the same way
+/// `start_entry`/`stop_entry` are scoped: stale frontend state, a duplicate
+/// resolve, or a retry naming some other (possibly already-closed) entry
+/// must not silently overwrite that row's `ended_at` or hijack the live
+/// timer onto an unrelated split.
+#[tauri::command]
+pub fn resolve_idle(
+    app: AppHandle,
+    db: State<Db>,
+    idle: State<IdleState>,
+    timer: State<TimerState>,
+    entry_id: i64,
+    keep: bool,
+    split_at: Option<i64>,
+) -> Result<(), String> {
+    if timer.entry_id() != Some(entry_id) {
+        return Err("entry_id is not the active entry".into());
+    }
+
+    if keep {
+        idle.take_pending(entry_id);
+        return Ok(());
+    }
+
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let (started_at, project, task): (i64, String, String) = conn
+        .query_row(
+            "SELECT started_at, project, task FROM entries WHERE id = ?1",
+            rusqlite::params![entry_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|e| e.to_string())?;
+
+    // Fall back to now only if there's no tracked idle span for this entry
+    // (e.g. `resolve_idle` called without a preceding idle-detected event).
+    let idle_started_at = idle.take_pending(entry_id).unwrap_or_else(unix_now);
+    let (cut, resume_at) = clamp_idle(started_at, idle_started_at, split_at, unix_now());
+
+    conn.execute(
+        "UPDATE entries SET ended_at = ?1 WHERE id = ?2",
+        rusqlite::params![cut, entry_id],
+    )
+    .map_err(|e| e.to_string())?;
+
+    if let Some(resume_at) = resume_at {
+        conn.execute(
+            "INSERT INTO entries (project, task, started_at, ended_at) VALUES (?1, ?2, ?3, NULL)",
+            rusqlite::params![project, task, resume_at],
+        )
+        .map_err(|e| e.to_string())?;
+        timer.rebind(conn.last_insert_rowid());
+    } else {
+        timer.unbind(entry_id);
+    }
+
+    drop(conn);
+    events::emit_state(&app, &timer);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cut_never_precedes_entry_start() {
+        // idle_started_at before started_at (clock skew, or a stale event)
+        // still clamps to the entry's own start.
+        let (cut, resume_at) = clamp_idle(1_000, 900, None, 2_000);
+        assert_eq!(cut, 1_000);
+        assert_eq!(resume_at, None);
+    }
+
+    #[test]
+    fn cut_uses_idle_start_when_later_than_entry_start() {
+        let (cut, _) = clamp_idle(1_000, 1_500, None, 2_000);
+        assert_eq!(cut, 1_500);
+    }
+
+    #[test]
+    fn split_resume_never_precedes_the_cut() {
+        // split_at falls before the idle span began; resume can't be
+        // earlier than where the entry was actually cut.
+        let (cut, resume_at) = clamp_idle(1_000, 1_500, Some(1_200), 2_000);
+        assert_eq!(cut, 1_500);
+        assert_eq!(resume_at, Some(1_500));
+    }
+
+    #[test]
+    fn split_resume_never_exceeds_now() {
+        let (_, resume_at) = clamp_idle(1_000, 1_500, Some(5_000), 2_000);
+        assert_eq!(resume_at, Some(2_000));
+    }
+
+    #[test]
+    fn split_resume_uses_split_at_when_already_clamped() {
+        let (_, resume_at) = clamp_idle(1_000, 1_500, Some(1_800), 3_000);
+        assert_eq!(resume_at, Some(1_800));
+    }
+}
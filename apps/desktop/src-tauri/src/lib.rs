@@ -1,11 +1,28 @@
 use tauri::Manager;
 
+mod db;
+mod deep_link;
+mod entries;
+mod events;
+mod idle;
+mod ready;
+mod shortcuts;
+mod splash;
+mod time;
+mod timer;
+mod tray;
+
+use timer::TimerState;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let mut builder = tauri::Builder::default().plugin(tauri_plugin_http::init());
     #[cfg(desktop)]
     {
-        builder = builder.plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+        builder = builder.plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+            if let Some(url) = args.iter().find(|arg| arg.contains("timesheet://")) {
+                deep_link::route(app, url);
+            }
             let _ = app
                 .get_webview_window("main")
                 .expect("no main window")
@@ -13,23 +30,57 @@ pub fn run() {
         }));
     }
     builder
+        .manage(TimerState::default())
+        .manage(idle::IdleState::default())
+        .manage(ready::Ready::default())
         .setup(|app| {
             #[cfg(desktop)]
             {
                 let _ = app.handle().plugin(tauri_plugin_positioner::init());
-                tauri::tray::TrayIconBuilder::new()
-                    .on_tray_icon_event(|tray_handle, event| {
-                        tauri_plugin_positioner::on_tray_event(tray_handle.app_handle(), &event);
-                    })
-                    .build(app)?;
+                tray::build(app.handle())?;
+                events::spawn_ticker(app.handle().clone());
+                idle::spawn_monitor(app.handle().clone());
+
+                let shortcut_config = shortcuts::load_config(app.handle());
+                shortcuts::register(app.handle(), &shortcut_config)?;
+
                 app.set_activation_policy(tauri::ActivationPolicy::Accessory);
+
+                if let Some(window) = app.get_webview_window("main") {
+                    let handle = app.handle().clone();
+                    window.on_window_event(move |event| {
+                        // `Focused(true)` alone only catches the
+                        // unfocused-to-focused transition; a user who stays
+                        // focused and keeps moving the pointer for longer
+                        // than `IDLE_THRESHOLD` would otherwise still get
+                        // flagged idle, so any in-window pointer movement
+                        // counts as activity too.
+                        match event {
+                            tauri::WindowEvent::Focused(true)
+                            | tauri::WindowEvent::CursorMoved { .. } => {
+                                idle::note_activity(&handle);
+                            }
+                            _ => {}
+                        }
+                    });
+                }
             }
 
+            splash::show(app.handle())?;
+
             Ok(())
         })
         .plugin(tauri_plugin_deep_link::init())
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![])
+        .invoke_handler(tauri::generate_handler![
+            entries::start_entry,
+            entries::stop_entry,
+            entries::list_entries,
+            entries::edit_entry,
+            entries::delete_entry,
+            idle::report_activity,
+            idle::resolve_idle,
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
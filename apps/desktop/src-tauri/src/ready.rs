@@ -0,0 +1,19 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Tracks whether backend warm-up (DB open/migrate, crash recovery) has
+/// finished. Code that can run before `setup()` returns — like a
+/// second-instance launch forwarding a deep link — must check this before
+/// touching anything warm-up manages (e.g. `Db`), since the real work
+/// happens in a spawned async task that may still be running.
+#[derive(Default)]
+pub struct Ready(AtomicBool);
+
+impl Ready {
+    pub fn get(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    pub fn set(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
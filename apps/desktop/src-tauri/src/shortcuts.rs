@@ -0,0 +1,131 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::entries;
+use crate::events;
+use crate::ready::Ready;
+use crate::timer::{TimerState, TimerStatus};
+
+const CONFIG_FILE: &str = "shortcuts.json";
+
+/// User-remappable hotkeys for controlling the active timer without
+/// focusing the window. Loaded from and persisted to the app config dir.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutConfig {
+    pub toggle: String,
+    pub pause: String,
+}
+
+impl Default for ShortcutConfig {
+    fn default() -> Self {
+        Self {
+            toggle: "CmdOrCtrl+Shift+S".into(),
+            pause: "CmdOrCtrl+Shift+P".into(),
+        }
+    }
+}
+
+fn config_path(app: &AppHandle) -> tauri::Result<PathBuf> {
+    let dir = app.path().app_config_dir()?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(CONFIG_FILE))
+}
+
+/// Loads shortcut bindings from the app config dir, falling back to (and
+/// persisting) the defaults if none exist yet.
+pub fn load_config(app: &AppHandle) -> ShortcutConfig {
+    let Ok(path) = config_path(app) else {
+        return ShortcutConfig::default();
+    };
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => {
+            let config = ShortcutConfig::default();
+            let _ = save_config(app, &config);
+            config
+        }
+    }
+}
+
+pub fn save_config(app: &AppHandle, config: &ShortcutConfig) -> tauri::Result<()> {
+    let path = config_path(app)?;
+    let contents = serde_json::to_string_pretty(config).expect("config is serializable");
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Parses a shortcut spec, falling back to `default` (and logging) if the
+/// user hand-edited `shortcuts.json` with something that isn't a valid
+/// accelerator string. A bad remap should never be able to keep the app
+/// from starting.
+fn parse_or_default(spec: &str, default: &str) -> Shortcut {
+    spec.parse().unwrap_or_else(|err| {
+        eprintln!("invalid shortcut `{spec}` ({err}), falling back to `{default}`");
+        default
+            .parse()
+            .expect("built-in default shortcut is always valid")
+    })
+}
+
+/// Registers the toggle/pause global shortcuts, wiring them to the shared
+/// [`TimerState`] and notifying the webview of the resulting state change.
+pub fn register(app: &AppHandle, config: &ShortcutConfig) -> tauri::Result<()> {
+    let defaults = ShortcutConfig::default();
+    let toggle = parse_or_default(&config.toggle, &defaults.toggle);
+    let pause = parse_or_default(&config.pause, &defaults.pause);
+
+    app.plugin(
+        tauri_plugin_global_shortcut::Builder::new()
+            .with_handler(move |app, shortcut, event| {
+                if event.state() != ShortcutState::Pressed {
+                    return;
+                }
+                let timer = app.state::<TimerState>();
+                if *shortcut == toggle {
+                    // Starting from idle goes through `entries::start_entry`
+                    // (with a placeholder project/task, same as the tray's
+                    // "start"), so a shortcut-started session is persisted
+                    // instead of left as an untracked, unstoppable timer.
+                    // The global shortcut is live the instant `setup()`
+                    // registers it, well before warm-up finishes managing
+                    // `Db`, so this has to wait on `Ready` the same way the
+                    // tray and deep links do.
+                    match timer.status() {
+                        TimerStatus::Idle => {
+                            if !app.state::<Ready>().get() {
+                                return;
+                            }
+                            let _ = entries::start_entry(
+                                app.clone(),
+                                app.state(),
+                                app.state(),
+                                "Unnamed".into(),
+                                "Unnamed".into(),
+                            );
+                        }
+                        TimerStatus::Running => {
+                            timer.pause();
+                            events::emit_state(app, &timer);
+                        }
+                        TimerStatus::Paused => {
+                            timer.resume();
+                            events::emit_state(app, &timer);
+                        }
+                    }
+                } else if *shortcut == pause {
+                    timer.pause();
+                    events::emit_state(app, &timer);
+                }
+            })
+            .build(),
+    )?;
+
+    app.global_shortcut().register(toggle)?;
+    app.global_shortcut().register(pause)?;
+
+    Ok(())
+}
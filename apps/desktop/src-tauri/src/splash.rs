@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+
+use crate::db::{self, Db};
+use crate::deep_link;
+use crate::ready::Ready;
+use crate::time::unix_now;
+use crate::timer::TimerState;
+
+const SPLASHSCREEN_LABEL: &str = "splashscreen";
+
+/// Shows a lightweight splashscreen immediately, then warms up the backend
+/// in a spawned async task before revealing the main window. This keeps the
+/// user from staring at a frozen/empty window while the DB migrates and
+/// state is restored.
+pub fn show(app: &AppHandle) -> tauri::Result<()> {
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.hide();
+    }
+
+    WebviewWindowBuilder::new(
+        app,
+        SPLASHSCREEN_LABEL,
+        WebviewUrl::App("splashscreen.html".into()),
+    )
+    .title("Timesheet")
+    .inner_size(360.0, 220.0)
+    .resizable(false)
+    .decorations(false)
+    .center()
+    .build()?;
+
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        warm_up(&handle).await;
+        handle.state::<Ready>().set();
+        deep_link::register(&handle);
+
+        if let Some(splash) = handle.get_webview_window(SPLASHSCREEN_LABEL) {
+            let _ = splash.close();
+        }
+        if let Some(main) = handle.get_webview_window("main") {
+            let _ = main.show();
+            let _ = main.set_focus();
+        }
+    });
+
+    Ok(())
+}
+
+/// Opens/migrates the SQLite store and restores any timer that was left
+/// running when the app last crashed or was force-quit, preserving its
+/// already-elapsed duration.
+async fn warm_up(app: &AppHandle) {
+    let db = db::init(app).expect("failed to open timesheet database");
+    let crash_recovered = find_running_entry(&db);
+    app.manage(db);
+
+    if let Some((id, project, task, started_at)) = crash_recovered {
+        let accumulated = Duration::from_secs((unix_now() - started_at).max(0) as u64);
+        app.state::<TimerState>()
+            .restore(id, project, task, accumulated);
+    }
+}
+
+fn find_running_entry(db: &Db) -> Option<(i64, String, String, i64)> {
+    db.0.lock()
+        .unwrap()
+        .query_row(
+            "SELECT id, project, task, started_at FROM entries WHERE ended_at IS NULL",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .ok()
+}
@@ -0,0 +1,213 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Current state of the shared timer, mirrored to the tray, global shortcuts,
+/// deep links, and the frontend so they all agree on what is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerStatus {
+    Idle,
+    Running,
+    Paused,
+}
+
+#[derive(Debug)]
+struct TimerInner {
+    status: TimerStatus,
+    entry_id: Option<i64>,
+    project: Option<String>,
+    task: Option<String>,
+    started_at: Option<Instant>,
+    accumulated: Duration,
+}
+
+impl Default for TimerInner {
+    fn default() -> Self {
+        Self {
+            status: TimerStatus::Idle,
+            entry_id: None,
+            project: None,
+            task: None,
+            started_at: None,
+            accumulated: Duration::ZERO,
+        }
+    }
+}
+
+/// Shared, `app.manage`-d timer state. All entry points that can affect the
+/// running timer (tray menu, global shortcuts, deep links, frontend
+/// commands) go through this single struct so there is one source of truth.
+#[derive(Debug, Default)]
+pub struct TimerState(Mutex<TimerInner>);
+
+impl TimerState {
+    pub fn start(&self, entry_id: i64, project: String, task: String) {
+        let mut inner = self.0.lock().unwrap();
+        inner.status = TimerStatus::Running;
+        inner.entry_id = Some(entry_id);
+        inner.project = Some(project);
+        inner.task = Some(task);
+        inner.started_at = Some(Instant::now());
+        inner.accumulated = Duration::ZERO;
+    }
+
+    /// Restores a timer that was left running before a crash, preserving
+    /// the wall-clock duration already elapsed instead of resetting it.
+    pub fn restore(&self, entry_id: i64, project: String, task: String, accumulated: Duration) {
+        let mut inner = self.0.lock().unwrap();
+        inner.status = TimerStatus::Running;
+        inner.entry_id = Some(entry_id);
+        inner.project = Some(project);
+        inner.task = Some(task);
+        inner.started_at = Some(Instant::now());
+        inner.accumulated = accumulated;
+    }
+
+    /// Clears the timer's binding to `entry_id` back to idle, used when an
+    /// idle span on that entry is discarded and the entry itself has just
+    /// been closed in the DB. No-ops if the timer has since moved on to a
+    /// different entry, so a stale resolve can't clobber a newer session.
+    pub fn unbind(&self, entry_id: i64) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.entry_id == Some(entry_id) {
+            *inner = TimerInner::default();
+        }
+    }
+
+    /// Re-points a running timer at a freshly-inserted entry, restarting the
+    /// elapsed count from now. Used when an idle split closes the old entry
+    /// and opens a new one to continue tracking, so the timer doesn't keep
+    /// ticking against (or later try to stop) the now-closed row.
+    pub fn rebind(&self, entry_id: i64) {
+        let mut inner = self.0.lock().unwrap();
+        inner.entry_id = Some(entry_id);
+        inner.started_at = Some(Instant::now());
+        inner.accumulated = Duration::ZERO;
+    }
+
+    pub fn pause(&self) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.status == TimerStatus::Running {
+            inner.accumulated += inner.started_at.take().map_or(Duration::ZERO, |at| at.elapsed());
+            inner.status = TimerStatus::Paused;
+        }
+    }
+
+    pub fn resume(&self) {
+        let mut inner = self.0.lock().unwrap();
+        if inner.status == TimerStatus::Paused {
+            inner.started_at = Some(Instant::now());
+            inner.status = TimerStatus::Running;
+        }
+    }
+
+    /// Stops the timer and returns the total elapsed duration.
+    pub fn stop(&self) -> Duration {
+        let mut inner = self.0.lock().unwrap();
+        let elapsed = inner.accumulated
+            + inner.started_at.take().map_or(Duration::ZERO, |at| at.elapsed());
+        inner.status = TimerStatus::Idle;
+        inner.entry_id = None;
+        inner.project = None;
+        inner.task = None;
+        inner.accumulated = Duration::ZERO;
+        elapsed
+    }
+
+    pub fn status(&self) -> TimerStatus {
+        self.0.lock().unwrap().status
+    }
+
+    pub fn entry_id(&self) -> Option<i64> {
+        self.0.lock().unwrap().entry_id
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        let inner = self.0.lock().unwrap();
+        inner.accumulated
+            + inner
+                .started_at
+                .map_or(Duration::ZERO, |at| at.elapsed())
+    }
+
+    pub fn label(&self) -> String {
+        let total = self.elapsed().as_secs();
+        format!("{:02}:{:02}:{:02}", total / 3600, (total % 3600) / 60, total % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread::sleep;
+
+    #[test]
+    fn start_runs_and_binds_the_given_entry() {
+        let timer = TimerState::default();
+        timer.start(1, "Acme".into(), "Billing".into());
+        assert_eq!(timer.status(), TimerStatus::Running);
+        assert_eq!(timer.entry_id(), Some(1));
+    }
+
+    #[test]
+    fn elapsed_does_not_advance_while_paused() {
+        let timer = TimerState::default();
+        timer.start(1, "Acme".into(), "Billing".into());
+        sleep(Duration::from_millis(20));
+        timer.pause();
+        assert_eq!(timer.status(), TimerStatus::Paused);
+        let paused_elapsed = timer.elapsed();
+        sleep(Duration::from_millis(20));
+        assert_eq!(timer.elapsed(), paused_elapsed);
+
+        timer.resume();
+        assert_eq!(timer.status(), TimerStatus::Running);
+        sleep(Duration::from_millis(20));
+        assert!(timer.elapsed() > paused_elapsed);
+    }
+
+    #[test]
+    fn resume_while_not_paused_is_a_no_op() {
+        let timer = TimerState::default();
+        assert_eq!(timer.status(), TimerStatus::Idle);
+        timer.resume();
+        assert_eq!(timer.status(), TimerStatus::Idle);
+    }
+
+    #[test]
+    fn stop_resets_to_idle_and_clears_the_entry() {
+        let timer = TimerState::default();
+        timer.start(1, "Acme".into(), "Billing".into());
+        sleep(Duration::from_millis(10));
+        let elapsed = timer.stop();
+        assert!(elapsed >= Duration::from_millis(10));
+        assert_eq!(timer.status(), TimerStatus::Idle);
+        assert_eq!(timer.entry_id(), None);
+    }
+
+    #[test]
+    fn unbind_only_clears_a_matching_entry() {
+        let timer = TimerState::default();
+        timer.start(1, "Acme".into(), "Billing".into());
+
+        timer.unbind(2);
+        assert_eq!(timer.status(), TimerStatus::Running);
+        assert_eq!(timer.entry_id(), Some(1));
+
+        timer.unbind(1);
+        assert_eq!(timer.status(), TimerStatus::Idle);
+        assert_eq!(timer.entry_id(), None);
+    }
+
+    #[test]
+    fn rebind_points_at_the_new_entry_and_restarts_elapsed() {
+        let timer = TimerState::default();
+        timer.start(1, "Acme".into(), "Billing".into());
+        sleep(Duration::from_millis(20));
+
+        timer.rebind(2);
+        assert_eq!(timer.status(), TimerStatus::Running);
+        assert_eq!(timer.entry_id(), Some(2));
+        assert!(timer.elapsed() < Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,95 @@
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::entries;
+use crate::events;
+use crate::ready::Ready;
+use crate::timer::{TimerState, TimerStatus};
+
+/// Builds the tray menu (Start/Pause/Stop/Open) and hooks it up to the
+/// shared [`TimerState`], so toggling from the tray stays in sync with the
+/// frontend.
+pub fn build(app: &AppHandle) -> tauri::Result<()> {
+    let start = MenuItem::with_id(app, "start", "Start", true, None::<&str>)?;
+    let pause = MenuItem::with_id(app, "pause", "Pause", true, None::<&str>)?;
+    let stop = MenuItem::with_id(app, "stop", "Stop", true, None::<&str>)?;
+    let open = MenuItem::with_id(app, "open", "Open", true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[&start, &pause, &stop, &open, &PredefinedMenuItem::quit(app, None)?],
+    )?;
+
+    TrayIconBuilder::with_id("main")
+        .menu(&menu)
+        .tooltip("Timesheet \u{2014} idle")
+        .on_tray_icon_event(|tray_handle, event| {
+            tauri_plugin_positioner::on_tray_event(tray_handle.app_handle(), &event);
+        })
+        .on_menu_event(|app, event| {
+            match event.id.as_ref() {
+                // Start/stop go through the same `entries` commands the
+                // frontend uses, so tray-initiated sessions are persisted
+                // like any other and never leave a dangling open row. Both
+                // reach `Db` through `State`, which isn't `app.manage`-d
+                // until warm-up finishes, so they no-op until `Ready` is
+                // set — same guard as `deep_link::route`.
+                "start" => {
+                    if !app.state::<Ready>().get() {
+                        return;
+                    }
+                    let timer = app.state::<TimerState>();
+                    match timer.status() {
+                        TimerStatus::Idle => {
+                            let _ = entries::start_entry(
+                                app.clone(),
+                                app.state(),
+                                app.state(),
+                                "Unnamed".into(),
+                                "Unnamed".into(),
+                            );
+                        }
+                        TimerStatus::Running => {
+                            timer.pause();
+                            events::emit_state(app, &timer);
+                        }
+                        TimerStatus::Paused => {
+                            timer.resume();
+                            events::emit_state(app, &timer);
+                        }
+                    }
+                }
+                "pause" => {
+                    let timer = app.state::<TimerState>();
+                    timer.pause();
+                    events::emit_state(app, &timer);
+                }
+                "stop" => {
+                    if !app.state::<Ready>().get() {
+                        return;
+                    }
+                    let _ = entries::stop_entry(app.clone(), app.state(), app.state());
+                }
+                "open" => {
+                    if let Some(window) = app.get_webview_window("main") {
+                        let _ = window.show();
+                        let _ = window.set_focus();
+                    }
+                }
+                _ => {}
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Refreshes the tray title/tooltip with the given label. Called once a
+/// second from the shared ticking task in [`events`] so the tray is always
+/// showing the same clock as the rest of the app.
+pub fn refresh<R: Runtime>(app: &AppHandle<R>, label: &str) {
+    if let Some(tray) = app.tray_by_id("main") {
+        let _ = tray.set_title(Some(label));
+        let _ = tray.set_tooltip(Some(label));
+    }
+}